@@ -22,12 +22,106 @@
 //!
 use std::{collections::HashMap, hash::BuildHasher};
 use tera::{to_value, try_get_value, Error, Value};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-/// Right-aligns the token to a given length.   
+/// Computes the true terminal column count of `text`, where fullwidth/wide
+/// characters (e.g. CJK ideographs) count as 2 columns and combining marks
+/// or zero-width joiners count as 0, instead of Rust's `char`-count notion
+/// of length used by `format!`'s own padding.
+fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Cuts `text` to `len` display columns, appending `ellipsis` inside that
+/// budget, without splitting a wide char in half. If there isn't even room
+/// for the ellipsis, it is dropped so the result never exceeds `len`
+/// columns.
+fn truncate_to_width(text: &str, len: usize, ellipsis: &str) -> String {
+    if len == 0 {
+        return String::new();
+    }
+    let ellipsis_width = display_width(ellipsis);
+    let (ellipsis, budget) = if ellipsis_width < len {
+        (ellipsis, len - ellipsis_width)
+    } else {
+        ("", len)
+    };
+    let mut out = String::new();
+    let mut width = 0;
+    for ch in text.chars() {
+        let w = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if width + w > budget {
+            break;
+        }
+        width += w;
+        out.push(ch);
+    }
+    out.push_str(ellipsis);
+    out
+}
+
+/// Reads the optional `fill` arg (a single char, default space) shared by
+/// all three align filters. `format!` only supports fill chars through
+/// compile-time format specs, so the align filters build their padding by
+/// hand using whatever char this returns.
+fn parse_fill<S: BuildHasher>(
+    args: &HashMap<String, Value, S>,
+    filter_name: &'static str,
+) -> tera::Result<char> {
+    match args.get("fill") {
+        Some(fill) => {
+            let fill = try_get_value!(filter_name, "fill", String, fill);
+            let mut chars = fill.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(Error::msg(format!(
+                    "Filter `{filter_name}` expected `fill` to be a single char, got `{fill}`",
+                ))),
+            }
+        }
+        None => Ok(' '),
+    }
+}
+
+/// Applies the optional `truncate=true`/`ellipsis="…"` args (shared by all
+/// three align filters) to `text`, cutting it to `len` display columns when
+/// it overflows. Leaves `text` untouched when `truncate` is absent/false or
+/// `text` already fits.
+fn maybe_truncate<S: BuildHasher>(
+    text: String,
+    len: usize,
+    args: &HashMap<String, Value, S>,
+    filter_name: &'static str,
+) -> tera::Result<String> {
+    let truncate = match args.get("truncate") {
+        Some(truncate) => try_get_value!(filter_name, "truncate", bool, truncate),
+        None => false,
+    };
+    if !truncate || display_width(&text) <= len {
+        return Ok(text);
+    }
+    let ellipsis = match args.get("ellipsis") {
+        Some(ellipsis) => try_get_value!(filter_name, "ellipsis", String, ellipsis),
+        None => "…".to_string(),
+    };
+    Ok(truncate_to_width(&text, len, &ellipsis))
+}
+
+/// Right-aligns the token to a given length.
 ///
 /// # Usage in Tera-Templates
 /// `{{ name | right_align(length=20) }}`
 ///
+/// Padding is based on display width, so CJK ideographs, combining accents
+/// and emoji line up correctly in fixed-width tables, not just the `char`
+/// count. If `text` is already at least `length` columns wide it is left
+/// untouched, unless `truncate=true` is passed, in which case it is cut to
+/// `length` columns with `ellipsis` (default `"…"`) appended inside that
+/// budget, e.g. `{{ name | right_align(length=10, truncate=true) }}`. The
+/// padding char defaults to a space but can be overridden with `fill`, e.g.
+/// `{{ score | right_align(length=10, fill='0') }}` for zero-padded
+/// numbers.
+///
 /// # Example
 ///
 /// ```
@@ -49,7 +143,14 @@ pub fn right_align<S: BuildHasher>(
     args: &HashMap<String, Value, S>,
 ) -> tera::Result<Value> {
     let (text, len) = eval_value(value, args, "right_align")?;
-    Ok(to_value(format!("{text:>len$}")).unwrap())
+    let text = maybe_truncate(text, len, args, "right_align")?;
+    let fill = parse_fill(args, "right_align")?;
+    let width = display_width(&text);
+    if width >= len {
+        return Ok(to_value(text).unwrap());
+    }
+    let pad: String = std::iter::repeat_n(fill, len - width).collect();
+    Ok(to_value(format!("{pad}{text}")).unwrap())
 }
 
 /// Left-aligns the token to a given length.
@@ -57,6 +158,9 @@ pub fn right_align<S: BuildHasher>(
 /// # Usage in Tera-Templates
 /// `{{ name | left_align(length=20) }}`
 ///
+/// Padding is based on display width (see [`right_align`]), so wide
+/// characters don't throw off the column count.
+///
 /// # Example
 ///
 /// ```
@@ -78,14 +182,25 @@ pub fn left_align<S: BuildHasher>(
     args: &HashMap<String, Value, S>,
 ) -> tera::Result<Value> {
     let (text, len) = eval_value(value, args, "left_align")?;
-    Ok(to_value(format!("{text:len$}")).unwrap())
+    let text = maybe_truncate(text, len, args, "left_align")?;
+    let fill = parse_fill(args, "left_align")?;
+    let width = display_width(&text);
+    if width >= len {
+        return Ok(to_value(text).unwrap());
+    }
+    let pad: String = std::iter::repeat_n(fill, len - width).collect();
+    Ok(to_value(format!("{text}{pad}")).unwrap())
 }
 
-/// Centers the token to a given length.   
+/// Centers the token to a given length.
 ///
 /// # Usage in Tera-Templates
 /// `{{ name | center(length=20) }}`
 ///
+/// Padding is based on display width (see [`right_align`]). When the
+/// remaining space can't be split evenly, the extra column goes to the
+/// right, matching `format!`'s own `{:^}` behaviour.
+///
 /// # Example
 ///
 /// ```
@@ -107,20 +222,470 @@ pub fn center<S: BuildHasher>(
     args: &HashMap<String, Value, S>,
 ) -> tera::Result<Value> {
     let (text, len) = eval_value(value, args, "center")?;
-    Ok(to_value(format!("{text:^len$}")).unwrap())
+    let text = maybe_truncate(text, len, args, "center")?;
+    let fill = parse_fill(args, "center")?;
+    let width = display_width(&text);
+    if width >= len {
+        return Ok(to_value(text).unwrap());
+    }
+    let remaining = len - width;
+    let left = remaining / 2;
+    let right = remaining - left;
+    let pad_left: String = std::iter::repeat_n(fill, left).collect();
+    let pad_right: String = std::iter::repeat_n(fill, right).collect();
+    Ok(to_value(format!("{pad_left}{text}{pad_right}")).unwrap())
 }
 
-fn eval_value<S: BuildHasher>(
+/// Right-aligns a number on its decimal point to a given length, first
+/// formatting it to a fixed number of fractional digits (padding with
+/// trailing zeros, or rounding, as needed).
+///
+/// # Usage in Tera-Templates
+/// `{{ score | decimal_align(length=10, decimals=2) }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::decimal_align;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", &760.5);
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("decimal_align", decimal_align);
+///
+/// let i = "{{ i | decimal_align(length=10, decimals=2) }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "    760.50");
+/// ```
+pub fn decimal_align<S: BuildHasher>(
     value: &Value,
     args: &HashMap<String, Value, S>,
-    filter_name: &'static str,
-) -> tera::Result<(String, usize)> {
+) -> tera::Result<Value> {
+    let len = match args.get("length") {
+        Some(length) => try_get_value!("decimal_align", "length", usize, length),
+        None => {
+            return Err(Error::msg(
+                "Filter `decimal_align` expected an arg called `length`",
+            ))
+        }
+    };
+    let decimals = match args.get("decimals") {
+        Some(decimals) => try_get_value!("decimal_align", "decimals", usize, decimals),
+        None => {
+            return Err(Error::msg(
+                "Filter `decimal_align` expected an arg called `decimals`",
+            ))
+        }
+    };
+    let number = match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::msg(format!(
+            "Filter `decimal_align` was called on an incorrect value: got `{value}` \
+                        but expected a number",
+        ))
+    })?;
+    let text = format!("{number:.decimals$}");
+    let width = display_width(&text);
+    if width >= len {
+        return Ok(to_value(text).unwrap());
+    }
+    let pad = " ".repeat(len - width);
+    Ok(to_value(format!("{pad}{text}")).unwrap())
+}
+
+/// Word-wraps a string to a fixed display-column width, using a greedy
+/// line-filling algorithm: words accumulate on a line until adding the
+/// next word (plus one space) would exceed `length` columns, then the line
+/// is emitted and a new one started. A single word wider than `length` is
+/// hard-broken at a column boundary that respects wide-char widths. Lines
+/// are joined with `\n`.
+///
+/// # Usage in Tera-Templates
+/// `{{ text | wrap(length=40) }}`
+///
+/// `indent` (default empty) is prepended to every line after the first, so
+/// a wrapped description cell lines up under its label. Pass
+/// `hanging=false` to also prepend `indent` to the first line instead of
+/// only the continuation lines (the default `hanging=true` leaves the
+/// first line for the label itself).
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::wrap;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "a somewhat long line of descriptive text");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("wrap", wrap);
+///
+/// let i = "{{ i | wrap(length=16) }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "a somewhat long\nline of\ndescriptive text");
+/// ```
+pub fn wrap<S: BuildHasher>(
+    value: &Value,
+    args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let (text, len) = eval_value(value, args, "wrap")?;
+    let indent = match args.get("indent") {
+        Some(indent) => try_get_value!("wrap", "indent", String, indent),
+        None => String::new(),
+    };
+    let hanging = match args.get("hanging") {
+        Some(hanging) => try_get_value!("wrap", "hanging", bool, hanging),
+        None => true,
+    };
+
+    let mut lines = wrap_lines(&text, len);
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    let wrapped = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 && hanging {
+                line.clone()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(to_value(wrapped).unwrap())
+}
+
+/// Greedily fills lines no wider than `len` display columns, breaking at
+/// whitespace. Words wider than `len` are hard-broken across several
+/// lines at a column boundary that never splits a wide char in half.
+fn wrap_lines(text: &str, len: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = display_width(word);
+        let extra = if current.is_empty() { 0 } else { 1 };
+        if current_width + extra + word_width <= len {
+            if extra == 1 {
+                current.push(' ');
+            }
+            current.push_str(word);
+            current_width += extra + word_width;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if word_width > len {
+            let mut chunks = hard_break(word, len);
+            current = chunks.pop().unwrap_or_default();
+            current_width = display_width(&current);
+            lines.append(&mut chunks);
+        } else {
+            current = word.to_string();
+            current_width = word_width;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits a single word into chunks no wider than `len` display columns.
+fn hard_break(word: &str, len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut width = 0;
+    for ch in word.chars() {
+        let w = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if width + w > len && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            width = 0;
+        }
+        current.push(ch);
+        width += w;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Splits `text` into words on case transitions (`myVar` -> `my`, `Var`),
+/// digit boundaries (`item2Value` -> `item`, `2`, `Value`), runs of
+/// consecutive uppercase letters followed by a lowercase one (`HTTPServer`
+/// -> `HTTP`, `Server`), spaces, `-` and `_` — the same segmentation
+/// `heck`-style case conversion crates use.
+fn segment_words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch == '-' || ch == '_' || ch.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if let Some(last) = current.chars().last() {
+            let boundary = (last.is_lowercase() && ch.is_uppercase())
+                || (last.is_uppercase()
+                    && ch.is_uppercase()
+                    && chars.get(i + 1).is_some_and(|next| next.is_lowercase()))
+                || (last.is_alphabetic() && ch.is_ascii_digit())
+                || (last.is_ascii_digit() && ch.is_alphabetic());
+            if boundary {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Upper-cases the first char of `word` and lower-cases the rest.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
+/// Converts the token to `snake_case`.
+///
+/// # Usage in Tera-Templates
+/// `{{ name | snake_case }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::snake_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "MyConfigValue");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("snake_case", snake_case);
+///
+/// let i = "{{ i | snake_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "my_config_value");
+/// ```
+pub fn snake_case<S: BuildHasher>(
+    value: &Value,
+    _args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let text = eval_text(value, "snake_case")?;
+    let words: Vec<_> = segment_words(&text)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect();
+    Ok(to_value(words.join("_")).unwrap())
+}
+
+/// Converts the token to `kebab-case`.
+///
+/// # Usage in Tera-Templates
+/// `{{ name | kebab_case }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::kebab_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "MyConfigValue");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("kebab_case", kebab_case);
+///
+/// let i = "{{ i | kebab_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "my-config-value");
+/// ```
+pub fn kebab_case<S: BuildHasher>(
+    value: &Value,
+    _args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let text = eval_text(value, "kebab_case")?;
+    let words: Vec<_> = segment_words(&text)
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect();
+    Ok(to_value(words.join("-")).unwrap())
+}
+
+/// Converts the token to `camelCase`.
+///
+/// # Usage in Tera-Templates
+/// `{{ name | camel_case }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::camel_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "my_config_value");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("camel_case", camel_case);
+///
+/// let i = "{{ i | camel_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "myConfigValue");
+/// ```
+pub fn camel_case<S: BuildHasher>(
+    value: &Value,
+    _args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let text = eval_text(value, "camel_case")?;
+    let rendered = segment_words(&text)
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if i == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect::<String>();
+    Ok(to_value(rendered).unwrap())
+}
+
+/// Converts the token to `Title Case`.
+///
+/// # Usage in Tera-Templates
+/// `{{ name | title_case }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::title_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "my_config_value");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("title_case", title_case);
+///
+/// let i = "{{ i | title_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "My Config Value");
+/// ```
+pub fn title_case<S: BuildHasher>(
+    value: &Value,
+    _args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let text = eval_text(value, "title_case")?;
+    let words: Vec<_> = segment_words(&text).iter().map(|w| capitalize(w)).collect();
+    Ok(to_value(words.join(" ")).unwrap())
+}
+
+/// Converts the token to `SHOUTY_SNAKE_CASE`.
+///
+/// # Usage in Tera-Templates
+/// `{{ name | shouty_snake_case }}`
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::shouty_snake_case;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert("i", "MyConfigValue");
+///
+/// let mut tera = Tera::default();
+/// tera.register_filter("shouty_snake_case", shouty_snake_case);
+///
+/// let i = "{{ i | shouty_snake_case }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(rendered, "MY_CONFIG_VALUE");
+/// ```
+pub fn shouty_snake_case<S: BuildHasher>(
+    value: &Value,
+    _args: &HashMap<String, Value, S>,
+) -> tera::Result<Value> {
+    let text = eval_text(value, "shouty_snake_case")?;
+    let words: Vec<_> = segment_words(&text)
+        .iter()
+        .map(|w| w.to_uppercase())
+        .collect();
+    Ok(to_value(words.join("_")).unwrap())
+}
+
+/// Registers every filter and function in this crate with `tera` in one
+/// call, so callers don't have to wire each one up by hand.
+///
+/// # Example
+///
+/// ```
+/// use tera::Tera;
+/// use tera_plaintext_filters::register_all;
+///
+/// let mut tera = Tera::default();
+/// register_all(&mut tera);
+/// ```
+pub fn register_all(tera: &mut tera::Tera) {
+    tera.register_filter("right_align", right_align);
+    tera.register_filter("left_align", left_align);
+    tera.register_filter("center", center);
+    tera.register_filter("decimal_align", decimal_align);
+    tera.register_filter("wrap", wrap);
+    tera.register_filter("snake_case", snake_case);
+    tera.register_filter("kebab_case", kebab_case);
+    tera.register_filter("camel_case", camel_case);
+    tera.register_filter("title_case", title_case);
+    tera.register_filter("shouty_snake_case", shouty_snake_case);
+    tera.register_function("table", table);
+}
+
+/// Extracts the text a filter operates on, rejecting objects/arrays with
+/// the shared `filter_name`-prefixed error message. Used directly by the
+/// case filters, and by [`eval_value`] for the align filters and `wrap`.
+fn eval_text(value: &Value, filter_name: &'static str) -> tera::Result<String> {
     if value.is_object() || value.is_array() {
         return Err(Error::msg(format!(
             "Filter `{filter_name}` was called on an incorrect value: got `{value}` \
                         but expected a text or number",
         )));
     }
+    Ok(match value.as_str() {
+        Some(str) => str.to_string(),
+        // null => ""
+        None if value.is_null() => String::new(),
+        None => value.to_string(),
+    })
+}
+
+fn eval_value<S: BuildHasher>(
+    value: &Value,
+    args: &HashMap<String, Value, S>,
+    filter_name: &'static str,
+) -> tera::Result<(String, usize)> {
+    let text = eval_text(value, filter_name)?;
     let len = match args.get("length") {
         Some(length) => {
             try_get_value!(filter_name, "length", usize, length)
@@ -131,12 +696,201 @@ fn eval_value<S: BuildHasher>(
             )))
         }
     };
-    Ok(match value.as_str() {
-        Some(str) => (str.to_string(), len),
-        // null => ""
-        None if value.is_null() => (String::new(), len),
-        None => (value.to_string(), len),
-    })
+    Ok((text, len))
+}
+
+/// Stringifies a cell value the way [`eval_value`] does for filters:
+/// strings as-is, `null`/missing as an empty string, anything else via its
+/// `Display` impl. Unlike [`eval_value`], objects and arrays are not
+/// rejected but stringified too.
+fn cell_text(value: Option<&Value>) -> String {
+    match value {
+        None => String::new(),
+        Some(value) if value.is_null() => String::new(),
+        Some(value) => match value.as_str() {
+            Some(str) => str.to_string(),
+            None => value.to_string(),
+        },
+    }
+}
+
+/// Pads or truncates `text` to exactly `width` display columns for the
+/// given `align` (`"left"`, `"center"` or `"right"`), the same logic the
+/// align filters use.
+fn pad_cell(text: &str, width: usize, align: &str) -> String {
+    let text = if display_width(text) > width {
+        truncate_to_width(text, width, "")
+    } else {
+        text.to_string()
+    };
+    let remaining = width.saturating_sub(display_width(&text));
+    match align {
+        "right" => format!("{}{text}", " ".repeat(remaining)),
+        "center" => {
+            let left = remaining / 2;
+            let right = remaining - left;
+            format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+        }
+        _ => format!("{text}{}", " ".repeat(remaining)),
+    }
+}
+
+/// Builds the `|---|`-style Markdown separator cell for a column of the
+/// given `width` (including its surrounding padding spaces), with colons
+/// placed to signal the column's alignment to Markdown renderers.
+fn separator_cell(align: &str, width: usize) -> String {
+    let width = width.max(3);
+    match align {
+        "right" => format!("{}:", "-".repeat(width - 1)),
+        "center" => format!(":{}:", "-".repeat(width - 2)),
+        _ => format!(":{}", "-".repeat(width - 1)),
+    }
+}
+
+struct TableColumn {
+    name: String,
+    key: String,
+    align: String,
+    width: usize,
+}
+
+/// Renders a full aligned Markdown table from an array of row objects.
+///
+/// # Usage in Tera-Templates
+/// `{{ table(rows=team, columns=columns) }}`
+///
+/// Each column needs a `name` (header text) and a `key` (looked up in each
+/// row object). `align` defaults to `"left"` and may be `"left"`,
+/// `"center"` or `"right"`. `width` is optional: when omitted, the column
+/// is auto-sized to the max display width of its header and all of its
+/// cell values; when given, cells are padded or truncated to fit it.
+///
+/// Tera's template grammar only has array literals, not object/map
+/// literals, so a `columns` value like
+/// `[{name: "No", key: "index", align: "right", width: 4}, ...]` can't be
+/// written inline inside a template call. Build it as a `serde_json::Value`
+/// in Rust instead and insert it into the context under its own key, as
+/// shown below.
+///
+/// # Example
+///
+/// ```
+/// use tera::{Context, Tera};
+/// use tera_plaintext_filters::table;
+///
+/// let mut ctx = Context::new();
+/// ctx.insert(
+///     "team",
+///     &serde_json::json!([
+///         {"index": "1.", "name": "Charly", "score": 3000},
+///         {"index": "2.", "name": "Alexander", "score": 800},
+///     ]),
+/// );
+/// ctx.insert(
+///     "columns",
+///     &serde_json::json!([
+///         {"name": "No", "key": "index", "align": "right"},
+///         {"name": "Name", "key": "name", "align": "center"},
+///         {"name": "Score", "key": "score", "align": "right"},
+///     ]),
+/// );
+///
+/// let mut tera = Tera::default();
+/// tera.register_function("table", table);
+///
+/// let i = "{{ table(rows=team, columns=columns) }}";
+/// let rendered = tera.render_str(i, &ctx).unwrap();
+/// assert_eq!(
+///     rendered,
+///     "| No |   Name    | Score |\n\
+///      |---:|:---------:|------:|\n\
+///      | 1. |  Charly   |  3000 |\n\
+///      | 2. | Alexander |   800 |"
+/// );
+/// ```
+pub fn table(args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let rows = match args.get("rows") {
+        Some(rows) => rows
+            .as_array()
+            .ok_or_else(|| Error::msg("Function `table` expected `rows` to be an array"))?,
+        None => return Err(Error::msg("Function `table` expected an arg called `rows`")),
+    };
+    let columns = match args.get("columns") {
+        Some(columns) => columns
+            .as_array()
+            .ok_or_else(|| Error::msg("Function `table` expected `columns` to be an array"))?,
+        None => {
+            return Err(Error::msg(
+                "Function `table` expected an arg called `columns`",
+            ))
+        }
+    };
+
+    let columns = columns
+        .iter()
+        .map(|column| {
+            let name = column
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    Error::msg("Function `table` expected each column to have a `name`")
+                })?
+                .to_string();
+            let key = column
+                .get("key")
+                .and_then(Value::as_str)
+                .ok_or_else(|| Error::msg("Function `table` expected each column to have a `key`"))?
+                .to_string();
+            let align = column
+                .get("align")
+                .and_then(Value::as_str)
+                .unwrap_or("left")
+                .to_string();
+            let width = column
+                .get("width")
+                .and_then(Value::as_u64)
+                .map(|w| w as usize);
+            let width = width.unwrap_or_else(|| {
+                rows.iter()
+                    .map(|row| display_width(&cell_text(row.get(&key))))
+                    .max()
+                    .unwrap_or(0)
+                    .max(display_width(&name))
+            });
+            Ok(TableColumn {
+                name,
+                key,
+                align,
+                width,
+            })
+        })
+        .collect::<tera::Result<Vec<TableColumn>>>()?;
+
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    lines.push(render_row(&columns, |col| col.name.clone()));
+    lines.push(format!(
+        "|{}|",
+        columns
+            .iter()
+            .map(|col| separator_cell(&col.align, col.width + 2))
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+    for row in rows {
+        lines.push(render_row(&columns, |col| cell_text(row.get(&col.key))));
+    }
+    Ok(to_value(lines.join("\n")).unwrap())
+}
+
+fn render_row(columns: &[TableColumn], cell: impl Fn(&TableColumn) -> String) -> String {
+    format!(
+        "|{}|",
+        columns
+            .iter()
+            .map(|col| format!(" {} ", pad_cell(&cell(col), col.width, &col.align)))
+            .collect::<Vec<_>>()
+            .join("|")
+    )
 }
 
 #[cfg(test)]
@@ -176,4 +930,208 @@ mod should {
         assert!(center(&json!({ "a": "notice", "b": 124.0 }), &hm).is_err());
         assert!(center(&json!(["notice", "the", "trailing", "comma -->",]), &hm).is_err());
     }
+
+    #[test]
+    fn align_by_display_width() {
+        // "日本語" is 3 chars but 6 display columns wide.
+        let v = json!("日本語");
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(10));
+        assert_eq!("日本語    ", left_align(&v, &hm).unwrap().as_str().unwrap());
+        assert_eq!(
+            "    日本語",
+            right_align(&v, &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!("  日本語  ", center(&v, &hm).unwrap().as_str().unwrap());
+
+        // Already wider than `length`: left untouched.
+        hm.insert("length".to_string(), json!(4));
+        assert_eq!("日本語", left_align(&v, &hm).unwrap().as_str().unwrap());
+    }
+
+    #[test]
+    fn truncate_overflowing_text() {
+        let v = json!("a very long description");
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(10));
+        hm.insert("truncate".to_string(), json!(true));
+        assert_eq!("a very lo…", left_align(&v, &hm).unwrap().as_str().unwrap());
+
+        hm.insert("ellipsis".to_string(), json!(".."));
+        assert_eq!("a very l..", left_align(&v, &hm).unwrap().as_str().unwrap());
+
+        // Fits already: not truncated, just padded as usual.
+        hm.insert("length".to_string(), json!(30));
+        assert_eq!(
+            "a very long description       ",
+            left_align(&v, &hm).unwrap().as_str().unwrap()
+        );
+
+        // Without `truncate`, overflowing text is still left as-is.
+        hm.remove("truncate");
+        hm.insert("length".to_string(), json!(10));
+        assert_eq!(
+            v.as_str().unwrap(),
+            left_align(&v, &hm).unwrap().as_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn pad_with_custom_fill() {
+        let v = json!(42);
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(5));
+        hm.insert("fill".to_string(), json!("0"));
+        assert_eq!("00042", right_align(&v, &hm).unwrap().as_str().unwrap());
+
+        let v = json!("Intro");
+        hm.insert("length".to_string(), json!(10));
+        hm.insert("fill".to_string(), json!("."));
+        assert_eq!("Intro.....", left_align(&v, &hm).unwrap().as_str().unwrap());
+
+        hm.insert("fill".to_string(), json!("--"));
+        assert!(left_align(&v, &hm).is_err());
+    }
+
+    #[test]
+    fn render_table() {
+        let mut args = HashMap::new();
+        args.insert(
+            "rows".to_string(),
+            json!([
+                {"index": "1.", "name": "Charly", "score": 3000},
+                {"index": "2.", "name": "Alexander", "score": 800},
+            ]),
+        );
+        args.insert(
+            "columns".to_string(),
+            json!([
+                {"name": "No", "key": "index", "align": "right"},
+                {"name": "Name", "key": "name", "align": "center"},
+                {"name": "Score", "key": "score", "align": "right"},
+            ]),
+        );
+        let rendered = table(&args).unwrap();
+        assert_eq!(
+            "| No |   Name    | Score |\n\
+             |---:|:---------:|------:|\n\
+             | 1. |  Charly   |  3000 |\n\
+             | 2. | Alexander |   800 |",
+            rendered.as_str().unwrap()
+        );
+
+        args.remove("rows");
+        assert!(table(&args).is_err());
+    }
+
+    #[test]
+    fn align_on_decimal_point() {
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(10));
+        hm.insert("decimals".to_string(), json!(2));
+
+        assert_eq!(
+            "   3000.00",
+            decimal_align(&json!(3000), &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!(
+            "    760.50",
+            decimal_align(&json!(760.5), &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!(
+            "     12.23",
+            decimal_align(&json!("12.23"), &hm)
+                .unwrap()
+                .as_str()
+                .unwrap()
+        );
+
+        assert!(decimal_align(&json!("not a number"), &hm).is_err());
+        assert!(decimal_align(&json!(null), &hm).is_err());
+    }
+
+    #[test]
+    fn wrap_text_greedily() {
+        let v = json!("a somewhat long line of descriptive text");
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(16));
+        assert_eq!(
+            "a somewhat long\nline of\ndescriptive text",
+            wrap(&v, &hm).unwrap().as_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn wrap_hard_breaks_overlong_words() {
+        let v = json!("supercalifragilisticexpialidocious");
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(10));
+        assert_eq!(
+            "supercalif\nragilistic\nexpialidoc\nious",
+            wrap(&v, &hm).unwrap().as_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn wrap_with_indent() {
+        let v = json!("a somewhat long line of descriptive text");
+        let mut hm = HashMap::new();
+        hm.insert("length".to_string(), json!(16));
+        hm.insert("indent".to_string(), json!("  "));
+        assert_eq!(
+            "a somewhat long\n  line of\n  descriptive text",
+            wrap(&v, &hm).unwrap().as_str().unwrap()
+        );
+
+        hm.insert("hanging".to_string(), json!(false));
+        assert_eq!(
+            "  a somewhat long\n  line of\n  descriptive text",
+            wrap(&v, &hm).unwrap().as_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn convert_case() {
+        let hm = HashMap::new();
+        let v = json!("MyConfigValue");
+        assert_eq!(
+            "my_config_value",
+            snake_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!(
+            "my-config-value",
+            kebab_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!(
+            "MY_CONFIG_VALUE",
+            shouty_snake_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+
+        let v = json!("my_config_value");
+        assert_eq!(
+            "myConfigValue",
+            camel_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+        assert_eq!(
+            "My Config Value",
+            title_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+
+        let v = json!("HTTPServer2Name");
+        assert_eq!(
+            "http_server_2_name",
+            snake_case(&v, &hm).unwrap().as_str().unwrap()
+        );
+    }
+
+    #[test]
+    fn register_all_filters_and_functions() {
+        let mut tera = tera::Tera::default();
+        register_all(&mut tera);
+
+        let mut ctx = tera::Context::new();
+        ctx.insert("i", "MyValue");
+        let rendered = tera.render_str("{{ i | snake_case }}", &ctx).unwrap();
+        assert_eq!("my_value", rendered);
+    }
 }